@@ -0,0 +1,58 @@
+//! User-configurable settings loaded from an optional config file.
+
+use std::path::{Path, PathBuf};
+
+use crate::error::CrabError;
+
+/// User settings that override crabtype's defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    theme: Option<String>,
+    custom_wordlist: Option<PathBuf>,
+}
+
+impl Config {
+    /// Loads a config from `path`, or returns the defaults if it doesn't exist.
+    pub fn load(path: &Path) -> Result<Config, CrabError> {
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+        let contents = std::fs::read_to_string(path)?;
+        Self::parse(&contents)
+    }
+
+    /// Parses a config from its on-disk `key = value` text.
+    fn parse(contents: &str) -> Result<Config, CrabError> {
+        let mut config = Config::default();
+        for (line_no, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or_else(|| {
+                CrabError::InvalidConfig(format!("line {}: expected 'key = value'", line_no + 1))
+            })?;
+            match key.trim() {
+                "theme" => config.theme = Some(value.trim().to_string()),
+                "wordlist" => config.custom_wordlist = Some(PathBuf::from(value.trim())),
+                other => {
+                    return Err(CrabError::InvalidConfig(format!(
+                        "unknown setting '{other}' on line {}",
+                        line_no + 1
+                    )))
+                }
+            }
+        }
+        Ok(config)
+    }
+
+    /// The user's chosen theme name, if any.
+    pub fn theme(&self) -> Option<&str> {
+        self.theme.as_deref()
+    }
+
+    /// The path to a user-supplied wordlist, if any.
+    pub fn custom_wordlist(&self) -> Option<&Path> {
+        self.custom_wordlist.as_deref()
+    }
+}
@@ -0,0 +1,46 @@
+//! A central error type so the crate can propagate failures with `?` instead
+//! of panicking on bad input.
+
+use std::fmt;
+use std::io;
+
+use crate::mode::UnknownMode;
+
+/// Errors that can occur while parsing CLI args, loading config, or loading
+/// wordlists.
+#[derive(Debug)]
+pub enum CrabError {
+    /// The CLI's mode argument didn't match a known mode.
+    UnknownMode(UnknownMode),
+    /// A named wordlist couldn't be found on disk.
+    WordlistNotFound(String),
+    /// The config file was malformed.
+    InvalidConfig(String),
+    /// An underlying I/O operation failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for CrabError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrabError::UnknownMode(err) => write!(f, "{err}"),
+            CrabError::WordlistNotFound(name) => write!(f, "wordlist '{name}' not found"),
+            CrabError::InvalidConfig(reason) => write!(f, "invalid config: {reason}"),
+            CrabError::Io(err) => write!(f, "io error: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for CrabError {}
+
+impl From<UnknownMode> for CrabError {
+    fn from(err: UnknownMode) -> Self {
+        CrabError::UnknownMode(err)
+    }
+}
+
+impl From<io::Error> for CrabError {
+    fn from(err: io::Error) -> Self {
+        CrabError::Io(err)
+    }
+}
@@ -1,15 +1,221 @@
 // crabtype - Rust implementation of zootype typing test
 
+mod config;
+mod error;
+mod mode;
+mod num2english;
+mod numbers;
+mod rng;
+mod stats;
+mod wordlist;
+
+use std::path::PathBuf;
+
+use config::Config;
+use error::CrabError;
+use mode::Mode;
+use wordlist::WordGenerator;
+
 const VERSION: &str = "dev";
 
 fn main() {
     let args: Vec<String> = std::env::args().collect();
-    
+
     if args.len() > 1 && (args[1] == "--version" || args[1] == "-v") {
         println!("crabtype {}", VERSION);
         return;
     }
-    
-    println!("Hello from crabtype");
+
+    if let Err(err) = run(&args) {
+        eprintln!("crabtype: {err}");
+        std::process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), CrabError> {
+    let config = Config::load(&config_path())?;
+    let theme = config
+        .theme()
+        .map_or_else(|| "default".to_string(), str::to_string);
+    println!("(using theme '{theme}')");
+
+    let list_flag = flag_value(args, "--list");
+    let gen_flag = flag_value(args, "--gen");
+    let flags = ["--list", "--gen"];
+    let positional: Vec<&String> = args
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !is_flag_arg(args, *index, &flags))
+        .map(|(_, arg)| arg)
+        .collect();
+
+    let Some(mode_arg) = positional.get(1) else {
+        println!("Hello from crabtype");
+        return Ok(());
+    };
+
+    let mode = Mode::parse(mode_arg, positional.get(2).map(|s| s.as_str()))?;
+
+    println!("Starting {mode} test...");
+    match mode {
+        Mode::Time(secs) => run_time_test(secs),
+        Mode::Words(count) => {
+            run_words_test(count, &config, list_flag.as_deref(), gen_flag.as_deref())?
+        }
+        Mode::Quote => run_quote_test(),
+        Mode::Zen => run_zen_test(),
+        Mode::Numbers(max) => run_numbers_test(max)?,
+    }
+
+    Ok(())
 }
 
+/// Returns the value passed to a `<flag> <value>` pair, if present.
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|index| args.get(index + 1))
+        .cloned()
+}
+
+/// Whether `args[index]` is part of one of `flags`' `<flag> <value>` pairs,
+/// so it can be excluded from positional argument parsing.
+fn is_flag_arg(args: &[String], index: usize, flags: &[&str]) -> bool {
+    if args.get(index).map(String::as_str).is_some_and(|arg| flags.contains(&arg)) {
+        return true;
+    }
+    index > 0
+        && args
+            .get(index - 1)
+            .map(String::as_str)
+            .is_some_and(|arg| flags.contains(&arg))
+}
+
+/// Where `crabtype` looks for its config file by default.
+fn config_path() -> PathBuf {
+    std::env::var("HOME")
+        .map(|home| PathBuf::from(home).join(".config/crabtype/config"))
+        .unwrap_or_else(|_| PathBuf::from("crabtype.conf"))
+}
+
+fn run_time_test(secs: u32) {
+    println!("(time test for {secs}s not yet implemented)");
+}
+
+fn run_words_test(
+    count: u32,
+    config: &Config,
+    list_name: Option<&str>,
+    gen_kind: Option<&str>,
+) -> Result<(), CrabError> {
+    let mut generator = active_word_generator(config, list_name, gen_kind)?;
+    let batch = generator.next_batch(count as usize);
+    let target = batch.join(" ");
+    println!("{target}");
+
+    let start = std::time::Instant::now();
+    let mut typed = String::new();
+    std::io::stdin().read_line(&mut typed)?;
+    let elapsed = start.elapsed();
+
+    let result = stats::score(&score_attempt(&target, typed.trim_end_matches('\n'), elapsed));
+    println!(
+        "wpm: {:.1} | raw: {:.1} | accuracy: {:.1}% | consistency: {:.1}",
+        result.wpm,
+        result.raw_wpm,
+        result.accuracy * 100.0,
+        result.consistency
+    );
+    Ok(())
+}
+
+/// Pairs a typed attempt up against its target text, character by character,
+/// spreading timestamps evenly across the elapsed time.
+///
+/// Reading a plain line from stdin only gives us a single end-of-line
+/// timestamp rather than real per-key timing (that needs raw-mode terminal
+/// input), so this is an approximation until the test loop reads keystrokes
+/// as they happen.
+fn score_attempt(target: &str, typed: &str, elapsed: std::time::Duration) -> Vec<stats::Keystroke> {
+    let target: Vec<char> = target.chars().collect();
+    let typed: Vec<char> = typed.chars().collect();
+    let total = target.len().max(typed.len()).max(1);
+    let elapsed_ms = elapsed.as_millis() as u64;
+
+    (0..total)
+        .map(|i| stats::Keystroke {
+            timestamp_ms: (i as u64 * elapsed_ms) / total as u64,
+            expected: target.get(i).copied().unwrap_or('\0'),
+            typed: typed.get(i).copied().unwrap_or('\0'),
+        })
+        .collect()
+}
+
+/// Picks the word generator to use for the words mode.
+///
+/// `--gen weighted`/`--gen numbers` select one of the other built-in
+/// generators outright; otherwise the `--list` flag takes priority over the
+/// config's custom wordlist, which takes priority over crabtype's small
+/// built-in list, all read through `RandomListGenerator`.
+fn active_word_generator(
+    config: &Config,
+    list_name: Option<&str>,
+    gen_kind: Option<&str>,
+) -> Result<Box<dyn WordGenerator>, CrabError> {
+    match gen_kind {
+        Some("weighted") => {
+            return Ok(Box::new(wordlist::FrequencyWeightedGenerator::default_weighted()))
+        }
+        Some("numbers") => return Ok(Box::new(wordlist::NumberGenerator::default_numbers())),
+        Some("random") | None => {}
+        Some(other) => {
+            return Err(CrabError::InvalidConfig(format!(
+                "unknown generator '{other}' (expected one of: random, weighted, numbers)"
+            )))
+        }
+    }
+
+    if let Some(name) = list_name {
+        let words = wordlist::load_named(name)?;
+        return Ok(Box::new(wordlist::RandomListGenerator::new(words)));
+    }
+    if let Some(path) = config.custom_wordlist() {
+        let words = wordlist::load(path)?;
+        return Ok(Box::new(wordlist::RandomListGenerator::new(words)));
+    }
+    Ok(Box::new(wordlist::RandomListGenerator::default_list()))
+}
+
+fn run_quote_test() {
+    println!("(quote test not yet implemented)");
+}
+
+fn run_zen_test() {
+    println!("(zen test not yet implemented)");
+}
+
+fn run_numbers_test(max: u64) -> Result<(), CrabError> {
+    const PROMPT_COUNT: usize = 10;
+    let prompts = numbers::generate_prompts(max, PROMPT_COUNT);
+    let target = prompts
+        .iter()
+        .map(|prompt| prompt.text.as_str())
+        .collect::<Vec<&str>>()
+        .join(" ");
+    println!("{target}");
+
+    let start = std::time::Instant::now();
+    let mut typed = String::new();
+    std::io::stdin().read_line(&mut typed)?;
+    let elapsed = start.elapsed();
+
+    let result = stats::score(&score_attempt(&target, typed.trim_end_matches('\n'), elapsed));
+    println!(
+        "wpm: {:.1} | raw: {:.1} | accuracy: {:.1}% | consistency: {:.1}",
+        result.wpm,
+        result.raw_wpm,
+        result.accuracy * 100.0,
+        result.consistency
+    );
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! The typing-test modes a user can select from the command line.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Which kind of typing test to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Type for a fixed duration, in seconds.
+    Time(u32),
+    /// Type a fixed number of words.
+    Words(u32),
+    /// Type a single quote pulled from the quote bank.
+    Quote,
+    /// Type freely with no limit and no score.
+    Zen,
+    /// Type numbers spelled out in English, up to a maximum value.
+    Numbers(u64),
+}
+
+/// The default duration for `Mode::Time` when no trailing argument is given.
+const DEFAULT_TIME_SECS: u32 = 30;
+/// The default word count for `Mode::Words` when no trailing argument is given.
+const DEFAULT_WORD_COUNT: u32 = 25;
+/// The default upper bound for `Mode::Numbers` when no trailing argument is given.
+const DEFAULT_NUMBERS_MAX: u64 = 9999;
+
+/// Returned when a mode string doesn't match any known mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownMode(String);
+
+impl fmt::Display for UnknownMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown mode '{}' (expected one of: time, words, quote, zen, numbers)",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for UnknownMode {}
+
+impl FromStr for Mode {
+    type Err = UnknownMode;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text {
+            "time" | "t" => Ok(Mode::Time(DEFAULT_TIME_SECS)),
+            "words" | "w" => Ok(Mode::Words(DEFAULT_WORD_COUNT)),
+            "quote" | "q" => Ok(Mode::Quote),
+            "zen" => Ok(Mode::Zen),
+            "numbers" | "n" => Ok(Mode::Numbers(DEFAULT_NUMBERS_MAX)),
+            other => Err(UnknownMode(other.to_string())),
+        }
+    }
+}
+
+impl Mode {
+    /// Parses a mode from its name plus an optional trailing numeric argument,
+    /// e.g. `Mode::parse("time", Some("60"))` or `Mode::parse("words", None)`.
+    ///
+    /// The trailing argument is ignored for modes that don't take one.
+    pub fn parse(text: &str, arg: Option<&str>) -> Result<Mode, UnknownMode> {
+        let mode = Mode::from_str(text)?;
+        Ok(match mode {
+            Mode::Time(default) => {
+                Mode::Time(arg.and_then(|a| a.parse().ok()).unwrap_or(default))
+            }
+            Mode::Words(default) => {
+                Mode::Words(arg.and_then(|a| a.parse().ok()).unwrap_or(default))
+            }
+            Mode::Numbers(default) => {
+                Mode::Numbers(arg.and_then(|a| a.parse().ok()).unwrap_or(default))
+            }
+            other => other,
+        })
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Time(secs) => write!(f, "time {secs}"),
+            Mode::Words(count) => write!(f, "words {count}"),
+            Mode::Quote => write!(f, "quote"),
+            Mode::Zen => write!(f, "zen"),
+            Mode::Numbers(max) => write!(f, "numbers (up to {max})"),
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Converts non-negative integers into their English-word spelling, using the
+//! Conway-Wechsler naming convention for large scale words.
+
+const ONES: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const TEENS: [&str; 10] = [
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Latin ones and tens roots used to build compound Conway-Wechsler scale
+/// names past "decillion", e.g. "un" + "dec" -> "undecillion" (scale index
+/// 12), "un" + "vigint" -> "unvigintillion" (scale index 22).
+const ILLIONS: [&str; 10] = [
+    "", "un", "duo", "tre", "quattuor", "quin", "sex", "septen", "octo", "novem",
+];
+const TENS_ILLIONS: [&str; 10] = [
+    "", "dec", "vigint", "trigint", "quadragint", "quinquagint", "sexagint", "septuagint",
+    "octogint", "nonagint",
+];
+
+/// Converts `n` into its English-word spelling, e.g. `21 -> "twenty-one"`,
+/// `1_000_000 -> "one million"`.
+pub fn to_english(n: u64) -> String {
+    if n == 0 {
+        return "zero".to_string();
+    }
+
+    let groups = to_groups_of_three(n);
+    let mut words = Vec::new();
+    let num_groups = groups.len();
+
+    for (index, group) in groups.iter().enumerate() {
+        if *group == 0 {
+            continue;
+        }
+        let scale_index = num_groups - 1 - index;
+        let mut phrase = group_to_english(*group);
+        if let Some(scale) = scale_word(scale_index) {
+            phrase.push(' ');
+            phrase.push_str(&scale);
+        }
+        words.push(phrase);
+    }
+
+    words.join(" ")
+}
+
+/// Splits `n` into base-1000 groups, most significant group first.
+fn to_groups_of_three(mut n: u64) -> Vec<u32> {
+    let mut groups = Vec::new();
+    while n > 0 {
+        groups.push((n % 1000) as u32);
+        n /= 1000;
+    }
+    groups.reverse();
+    groups
+}
+
+/// Spells a single group in `1..=999`.
+fn group_to_english(group: u32) -> String {
+    let hundreds = group / 100;
+    let remainder = group % 100;
+
+    let mut parts = Vec::new();
+    if hundreds > 0 {
+        parts.push(format!("{} hundred", ONES[hundreds as usize]));
+    }
+    if remainder > 0 {
+        parts.push(two_digits_to_english(remainder));
+    }
+    parts.join(" ")
+}
+
+/// Spells a value in `1..=99`.
+fn two_digits_to_english(n: u32) -> String {
+    if n < 10 {
+        ONES[n as usize].to_string()
+    } else if n < 20 {
+        TEENS[(n - 10) as usize].to_string()
+    } else {
+        let tens = TENS[(n / 10) as usize];
+        match n % 10 {
+            0 => tens.to_string(),
+            ones => format!("{tens}-{}", ONES[ones as usize]),
+        }
+    }
+}
+
+/// Returns the scale word for a group index (0 = units, 1 = thousand, 2 =
+/// million, ...), or `None` for the units group itself.
+fn scale_word(scale_index: usize) -> Option<String> {
+    match scale_index {
+        0 => None,
+        1 => Some("thousand".to_string()),
+        _ => Some(illion(scale_index)),
+    }
+}
+
+/// Builds the Conway-Wechsler "-illion" name for scale index `n` (2 =
+/// million, 3 = billion, ...) from its ones/tens/hundreds Latin roots.
+fn illion(n: usize) -> String {
+    // Scale index n corresponds to 10^(3n), and the Conway-Wechsler system
+    // names 10^(3*(k+2)) via the Latin cardinal for k: million is k=0,
+    // billion is k=1, and so on.
+    let k = n - 2;
+    if k < 10 {
+        return format!("{}illion", MILLION_ROOTS[k]);
+    }
+
+    // Beyond the common ten names, the compound scale name is built from the
+    // digits of the 1-indexed Latin cardinal itself (million = 1, decillion =
+    // 10, undecillion = 11, ...), not from `k`.
+    let cardinal = k + 1;
+    let ones = cardinal % 10;
+    let tens = (cardinal / 10) % 10;
+    let hundreds = cardinal / 100;
+
+    let mut root = String::new();
+    if hundreds > 0 {
+        root.push_str(HUNDREDS_ILLIONS[hundreds]);
+    }
+    if tens > 0 {
+        root.push_str(&elided(ILLIONS[ones], TENS_ILLIONS[tens]));
+    } else {
+        root.push_str(ILLIONS[ones]);
+    }
+    format!("{root}illion")
+}
+
+/// Latin cardinal roots for the common scale words (million through
+/// decillion), indexed by k = scale_index - 2.
+const MILLION_ROOTS: [&str; 10] = [
+    "m", "b", "tr", "quadr", "quint", "sext", "sept", "oct", "non", "dec",
+];
+
+/// Latin hundreds roots, indexed by hundreds digit; unused below 100.
+const HUNDREDS_ILLIONS: [&str; 10] = [
+    "", "cent", "ducent", "trecent", "quadringent", "quingent", "sescent", "septingent",
+    "octingent", "nongent",
+];
+
+/// Joins a ones root onto a tens root, eliding a doubled vowel as the
+/// Conway-Wechsler naming convention does (e.g. "duo" + "octogint" ->
+/// "duoctogint", not "duooctogint").
+fn elided(ones_root: &str, tens_root: &str) -> String {
+    if ones_root.ends_with('o') && tens_root.starts_with('o') {
+        format!("{}{}", &ones_root[..ones_root.len() - 1], tens_root)
+    } else {
+        format!("{ones_root}{tens_root}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_is_spelled_zero() {
+        assert_eq!(to_english(0), "zero");
+    }
+
+    #[test]
+    fn ones_and_teens() {
+        assert_eq!(to_english(5), "five");
+        assert_eq!(to_english(10), "ten");
+        assert_eq!(to_english(17), "seventeen");
+    }
+
+    #[test]
+    fn tens_hyphenate_with_ones() {
+        assert_eq!(to_english(20), "twenty");
+        assert_eq!(to_english(21), "twenty-one");
+        assert_eq!(to_english(99), "ninety-nine");
+    }
+
+    #[test]
+    fn hundreds_and_thousands() {
+        assert_eq!(to_english(100), "one hundred");
+        assert_eq!(to_english(123), "one hundred twenty-three");
+        assert_eq!(to_english(1000), "one thousand");
+        assert_eq!(to_english(1001), "one thousand one");
+        assert_eq!(to_english(21_000), "twenty-one thousand");
+    }
+
+    #[test]
+    fn large_scale_names() {
+        assert_eq!(to_english(1_000_000), "one million");
+        assert_eq!(to_english(1_000_000_000), "one billion");
+        assert_eq!(to_english(1_000_000_000_000), "one trillion");
+        assert_eq!(
+            to_english(u64::MAX),
+            "eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four \
+             trillion seventy-three billion seven hundred nine million five hundred fifty-one \
+             thousand six hundred fifteen"
+        );
+    }
+
+    #[test]
+    fn compound_scale_names_past_the_common_ten() {
+        // Unreachable from `to_english` on a u64 (which tops out around
+        // quintillion), but the naming itself should still be correct.
+        assert_eq!(illion(11), "decillion");
+        assert_eq!(illion(12), "undecillion");
+        assert_eq!(illion(13), "duodecillion");
+        assert_eq!(illion(22), "unvigintillion");
+    }
+}
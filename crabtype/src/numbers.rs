@@ -0,0 +1,25 @@
+//! Numbers practice mode: generates target numbers and their English spelling.
+
+use crate::num2english::to_english;
+use crate::rng::Rng;
+
+/// One prompt in the numbers mode: a target value and the text to type for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberPrompt {
+    pub value: u64,
+    pub text: String,
+}
+
+/// Generates `count` prompts with values in `0..=max`, spelled as English words.
+pub fn generate_prompts(max: u64, count: usize) -> Vec<NumberPrompt> {
+    let mut rng = Rng::seeded();
+    (0..count)
+        .map(|_| {
+            let value = rng.range(max);
+            NumberPrompt {
+                text: to_english(value),
+                value,
+            }
+        })
+        .collect()
+}
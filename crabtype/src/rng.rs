@@ -0,0 +1,36 @@
+//! A tiny seeded PRNG shared by practice modes that need randomness, so the
+//! crate doesn't need an external RNG crate for simple sampling.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A xorshift64 generator, seeded from the system clock.
+pub struct Rng(u64);
+
+impl Rng {
+    /// Builds a generator seeded from the current time.
+    pub fn seeded() -> Rng {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0x2545F4914F6CDD1D);
+        Rng(seed | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a value in `0..=max`.
+    pub fn range(&mut self, max: u64) -> u64 {
+        match max {
+            0 => 0,
+            // `max + 1` would overflow here, but 0..=u64::MAX is just every
+            // value next_u64() can produce.
+            u64::MAX => self.next_u64(),
+            max => self.next_u64() % (max + 1),
+        }
+    }
+}
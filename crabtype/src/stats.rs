@@ -0,0 +1,191 @@
+//! Scoring for a completed typing test: WPM, accuracy, and consistency.
+
+/// A single keystroke recorded during a test.
+#[derive(Debug, Clone, Copy)]
+pub struct Keystroke {
+    /// Milliseconds since the test started.
+    pub timestamp_ms: u64,
+    /// The character the test expected at this position.
+    pub expected: char,
+    /// The character the user actually typed.
+    pub typed: char,
+}
+
+impl Keystroke {
+    /// Whether the typed character matched what was expected.
+    pub fn is_correct(&self) -> bool {
+        self.typed == self.expected
+    }
+}
+
+/// The final score for a completed test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TestResult {
+    /// Net words per minute, counting only correct characters.
+    pub wpm: f64,
+    /// Raw words per minute, counting every typed character.
+    pub raw_wpm: f64,
+    /// Fraction of keystrokes that were correct, in `0.0..=1.0`.
+    pub accuracy: f64,
+    /// How steady the typing speed was over the run, in `0.0..=100.0`.
+    pub consistency: f64,
+}
+
+/// The conventional word length used to convert characters into "words" for WPM.
+const CHARS_PER_WORD: f64 = 5.0;
+
+/// Computes a [`TestResult`] from the full sequence of keystrokes in a run.
+///
+/// Returns a zeroed result if no keystrokes were recorded, and zero WPM and
+/// raw WPM if no time elapsed, rather than dividing by zero.
+pub fn score(keystrokes: &[Keystroke]) -> TestResult {
+    if keystrokes.is_empty() {
+        return TestResult {
+            wpm: 0.0,
+            raw_wpm: 0.0,
+            accuracy: 0.0,
+            consistency: 0.0,
+        };
+    }
+
+    let elapsed_ms = keystrokes.last().unwrap().timestamp_ms;
+    let minutes = elapsed_ms as f64 / 60_000.0;
+
+    let correct_chars = keystrokes.iter().filter(|k| k.is_correct()).count() as f64;
+    let total_chars = keystrokes.len() as f64;
+
+    let (wpm, raw_wpm) = if minutes > 0.0 {
+        (
+            (correct_chars / CHARS_PER_WORD) / minutes,
+            (total_chars / CHARS_PER_WORD) / minutes,
+        )
+    } else {
+        (0.0, 0.0)
+    };
+
+    let accuracy = correct_chars / total_chars;
+    let consistency = consistency_score(&per_second_wpm(keystrokes));
+
+    TestResult {
+        wpm,
+        raw_wpm,
+        accuracy,
+        consistency,
+    }
+}
+
+/// Buckets keystrokes into one-second windows and returns the raw WPM sample
+/// for each window up to the last keystroke; windows with no keystrokes
+/// count as 0 WPM.
+fn per_second_wpm(keystrokes: &[Keystroke]) -> Vec<f64> {
+    let mut buckets: Vec<u64> = Vec::new();
+    for keystroke in keystrokes {
+        let bucket = (keystroke.timestamp_ms / 1000) as usize;
+        if bucket >= buckets.len() {
+            buckets.resize(bucket + 1, 0);
+        }
+        buckets[bucket] += 1;
+    }
+
+    buckets
+        .into_iter()
+        .map(|chars| (chars as f64 / CHARS_PER_WORD) * 60.0)
+        .collect()
+}
+
+/// Derives a 0-100 consistency score from the coefficient of variation of a
+/// per-second WPM series: `100 * (1 - stddev / mean)`, clamped to `0.0..=100.0`.
+fn consistency_score(samples: &[f64]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    if mean == 0.0 {
+        return 0.0;
+    }
+
+    let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+    let stddev = variance.sqrt();
+
+    (100.0 * (1.0 - stddev / mean)).clamp(0.0, 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keystroke(timestamp_ms: u64, expected: char, typed: char) -> Keystroke {
+        Keystroke {
+            timestamp_ms,
+            expected,
+            typed,
+        }
+    }
+
+    #[test]
+    fn no_keystrokes_scores_as_zero_without_dividing_by_zero() {
+        let result = score(&[]);
+        assert_eq!(result.wpm, 0.0);
+        assert_eq!(result.raw_wpm, 0.0);
+        assert_eq!(result.accuracy, 0.0);
+        assert_eq!(result.consistency, 0.0);
+    }
+
+    #[test]
+    fn zero_elapsed_time_scores_as_zero_wpm() {
+        let keystrokes = vec![keystroke(0, 'a', 'a'), keystroke(0, 'b', 'b')];
+        let result = score(&keystrokes);
+        assert_eq!(result.wpm, 0.0);
+        assert_eq!(result.raw_wpm, 0.0);
+        assert_eq!(result.accuracy, 1.0);
+    }
+
+    #[test]
+    fn perfect_run_has_full_accuracy_and_matching_wpm() {
+        // 10 correct chars, last keystroke at 10.8s: (10/5) / (10.8/60) wpm.
+        let keystrokes: Vec<Keystroke> = (0..10)
+            .map(|i| keystroke(i * 1200, 'a', 'a'))
+            .collect();
+        let result = score(&keystrokes);
+        let expected_wpm = (10.0 / 5.0) / (10_800.0 / 60_000.0);
+        assert_eq!(result.accuracy, 1.0);
+        assert!((result.wpm - expected_wpm).abs() < 1e-9);
+        assert_eq!(result.wpm, result.raw_wpm);
+    }
+
+    #[test]
+    fn mistakes_lower_accuracy_but_not_raw_wpm() {
+        let keystrokes = vec![
+            keystroke(0, 'a', 'a'),
+            keystroke(1000, 'b', 'x'),
+            keystroke(2000, 'c', 'c'),
+        ];
+        let result = score(&keystrokes);
+        assert!(result.accuracy < 1.0);
+        assert!(result.raw_wpm > result.wpm);
+    }
+
+    #[test]
+    fn steady_typing_scores_high_consistency() {
+        let keystrokes: Vec<Keystroke> = (0..20)
+            .map(|i| keystroke(i * 200, 'a', 'a'))
+            .collect();
+        let result = score(&keystrokes);
+        assert!(result.consistency > 90.0, "{}", result.consistency);
+    }
+
+    #[test]
+    fn consistency_is_never_negative() {
+        // A single burst then nothing gives a high-variance series; the
+        // score should clamp at 0, not go negative.
+        let keystrokes = vec![
+            keystroke(0, 'a', 'a'),
+            keystroke(1, 'b', 'b'),
+            keystroke(1, 'c', 'c'),
+            keystroke(9000, 'd', 'd'),
+        ];
+        let result = score(&keystrokes);
+        assert!((0.0..=100.0).contains(&result.consistency));
+    }
+}
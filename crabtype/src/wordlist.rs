@@ -0,0 +1,234 @@
+//! Word sources for the words practice mode: static on-disk lists and the
+//! `WordGenerator` trait that built-in and user-provided generators share.
+
+use std::path::{Path, PathBuf};
+
+use crate::error::CrabError;
+use crate::num2english::to_english;
+use crate::rng::Rng;
+
+/// A small built-in fallback list, used when no config or `--list` flag
+/// names a wordlist to load.
+const DEFAULT_WORDS: &[&str] = &[
+    "the", "quick", "brown", "fox", "jumps", "over", "lazy", "dog", "pack", "my", "box", "with",
+    "five", "dozen", "liquor", "jugs", "time", "words", "zen", "type",
+];
+
+/// A small built-in weighted list, used by `--gen weighted` so it has
+/// something to draw from without requiring a config file.
+const DEFAULT_WEIGHTED_WORDS: &[(&str, u32)] = &[
+    ("the", 10),
+    ("of", 8),
+    ("and", 8),
+    ("a", 7),
+    ("to", 7),
+    ("in", 6),
+    ("is", 5),
+    ("you", 5),
+    ("that", 4),
+    ("it", 4),
+];
+
+/// The default upper bound for `--gen numbers`, for mixing number drills
+/// into a words-mode session.
+const DEFAULT_GENERATOR_NUMBERS_MAX: u64 = 9999;
+
+/// Produces batches of words for the words practice mode.
+///
+/// Built-in generators (random-from-list, frequency-weighted, numbers) and
+/// user-provided ones all implement this so the mode loop doesn't care which
+/// kind is active.
+pub trait WordGenerator {
+    /// Returns the next `n` words to type.
+    fn next_batch(&mut self, n: usize) -> Vec<String>;
+}
+
+/// Loads a newline-separated, UTF-8 wordlist from `path`.
+pub fn load(path: &Path) -> Result<Vec<String>, CrabError> {
+    if !path.exists() {
+        return Err(CrabError::WordlistNotFound(path.display().to_string()));
+    }
+    let contents = std::fs::read_to_string(path)?;
+    Ok(parse(&contents))
+}
+
+/// Parses a wordlist's on-disk text: one word per line, blank lines skipped.
+fn parse(contents: &str) -> Vec<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Where `crabtype` looks for named wordlists by default.
+pub fn lists_dir() -> PathBuf {
+    std::env::var("HOME")
+        .map(|home| PathBuf::from(home).join(".config/crabtype/lists"))
+        .unwrap_or_else(|_| PathBuf::from("lists"))
+}
+
+/// Loads the wordlist registered under `name`, e.g. `"animals"` loads
+/// `<lists_dir>/animals.txt`.
+pub fn load_named(name: &str) -> Result<Vec<String>, CrabError> {
+    validate_list_name(name)?;
+    load(&lists_dir().join(format!("{name}.txt")))
+}
+
+/// Rejects list names that could escape [`lists_dir`] (path separators, `.`,
+/// or `..`), since the name comes straight from the `--list` flag.
+fn validate_list_name(name: &str) -> Result<(), CrabError> {
+    let is_safe = !name.is_empty()
+        && !name.contains('/')
+        && !name.contains('\\')
+        && name != "."
+        && name != "..";
+    if is_safe {
+        Ok(())
+    } else {
+        Err(CrabError::WordlistNotFound(name.to_string()))
+    }
+}
+
+/// Picks uniformly at random from a fixed word list.
+pub struct RandomListGenerator {
+    words: Vec<String>,
+    rng: Rng,
+}
+
+impl RandomListGenerator {
+    pub fn new(words: Vec<String>) -> RandomListGenerator {
+        RandomListGenerator {
+            words,
+            rng: Rng::seeded(),
+        }
+    }
+
+    /// A generator over crabtype's small built-in word list.
+    pub fn default_list() -> RandomListGenerator {
+        RandomListGenerator::new(DEFAULT_WORDS.iter().map(|w| w.to_string()).collect())
+    }
+}
+
+impl WordGenerator for RandomListGenerator {
+    fn next_batch(&mut self, n: usize) -> Vec<String> {
+        if self.words.is_empty() {
+            return Vec::new();
+        }
+        (0..n)
+            .map(|_| {
+                let index = self.rng.range(self.words.len() as u64 - 1) as usize;
+                self.words[index].clone()
+            })
+            .collect()
+    }
+}
+
+/// Picks from a fixed word list, weighted by how frequently each word should
+/// appear (e.g. common words weighted higher than rare ones).
+pub struct FrequencyWeightedGenerator {
+    words: Vec<(String, u32)>,
+    total_weight: u64,
+    rng: Rng,
+}
+
+impl FrequencyWeightedGenerator {
+    pub fn new(words: Vec<(String, u32)>) -> FrequencyWeightedGenerator {
+        let total_weight = words.iter().map(|(_, weight)| *weight as u64).sum();
+        FrequencyWeightedGenerator {
+            words,
+            total_weight,
+            rng: Rng::seeded(),
+        }
+    }
+
+    fn pick(&mut self) -> String {
+        if self.total_weight == 0 {
+            return String::new();
+        }
+        let mut target = self.rng.range(self.total_weight - 1);
+        for (word, weight) in &self.words {
+            if target < *weight as u64 {
+                return word.clone();
+            }
+            target -= *weight as u64;
+        }
+        self.words
+            .last()
+            .map(|(word, _)| word.clone())
+            .unwrap_or_default()
+    }
+
+    /// A generator over crabtype's small built-in weighted word list.
+    pub fn default_weighted() -> FrequencyWeightedGenerator {
+        FrequencyWeightedGenerator::new(
+            DEFAULT_WEIGHTED_WORDS
+                .iter()
+                .map(|(word, weight)| (word.to_string(), *weight))
+                .collect(),
+        )
+    }
+}
+
+impl WordGenerator for FrequencyWeightedGenerator {
+    fn next_batch(&mut self, n: usize) -> Vec<String> {
+        (0..n).map(|_| self.pick()).collect()
+    }
+}
+
+/// Generates numbers spelled out in English, for mixing number drills into a
+/// words-mode session.
+pub struct NumberGenerator {
+    max: u64,
+    rng: Rng,
+}
+
+impl NumberGenerator {
+    pub fn new(max: u64) -> NumberGenerator {
+        NumberGenerator {
+            max,
+            rng: Rng::seeded(),
+        }
+    }
+
+    /// A generator using crabtype's default number range.
+    pub fn default_numbers() -> NumberGenerator {
+        NumberGenerator::new(DEFAULT_GENERATOR_NUMBERS_MAX)
+    }
+}
+
+impl WordGenerator for NumberGenerator {
+    fn next_batch(&mut self, n: usize) -> Vec<String> {
+        (0..n).map(|_| to_english(self.rng.range(self.max))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_names_are_valid() {
+        assert!(validate_list_name("animals").is_ok());
+        assert!(validate_list_name("top-1000").is_ok());
+    }
+
+    #[test]
+    fn traversal_attempts_are_rejected() {
+        assert!(validate_list_name("../../../etc/passwd").is_err());
+        assert!(validate_list_name("..").is_err());
+        assert!(validate_list_name(".").is_err());
+        assert!(validate_list_name("sub/dir").is_err());
+        assert!(validate_list_name("sub\\dir").is_err());
+        assert!(validate_list_name("").is_err());
+    }
+
+    #[test]
+    fn load_named_rejects_a_traversal_attempt_before_touching_disk() {
+        assert!(matches!(
+            load_named("../../../../../../etc/passwd"),
+            Err(CrabError::WordlistNotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,76 @@
+//! End-to-end tests that launch the compiled `crabtype` binary.
+
+mod common;
+
+use common::*;
+
+#[test]
+fn version_flag_prints_version_and_exits_cleanly() -> TestResult {
+    crabtype()?
+        .arg("--version")
+        .assert()
+        .success()
+        .stdout("crabtype dev\n");
+    Ok(())
+}
+
+#[test]
+fn short_version_flag_also_prints_version() -> TestResult {
+    crabtype()?
+        .arg("-v")
+        .assert()
+        .success()
+        .stdout("crabtype dev\n");
+    Ok(())
+}
+
+#[test]
+fn unknown_mode_exits_with_a_nonzero_status() -> TestResult {
+    crabtype()?
+        .arg("not-a-real-mode")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("unknown mode"));
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn time_mode_accepts_any_positive_duration(secs in 1u32..10_000) {
+        let mut cmd = crabtype().unwrap();
+        cmd.args(["time", &secs.to_string()]);
+        cmd.assert().success();
+    }
+
+    #[test]
+    fn words_mode_accepts_any_positive_word_count(count in 1u32..10_000) {
+        let mut cmd = crabtype().unwrap();
+        cmd.args(["words", &count.to_string()]);
+        cmd.assert().success();
+    }
+}
+
+#[test]
+fn words_mode_reports_exact_accuracy_for_a_scripted_run() -> TestResult {
+    // Point `--list` at a temp wordlist of one repeated word so the target
+    // text is fully known, then script stdin with a known number of wrong
+    // characters and assert the exact accuracy. WPM still depends on
+    // wall-clock timing, so we only pin down accuracy here.
+    let home = std::env::temp_dir().join(format!("crabtype-test-home-{}", std::process::id()));
+    let lists_dir = home.join(".config/crabtype/lists");
+    std::fs::create_dir_all(&lists_dir)?;
+    std::fs::write(lists_dir.join("repeat.txt"), "hello\n".repeat(5))?;
+
+    // Target: "hello hello hello hello hello" (29 chars). Typed flips the
+    // first and last characters, so 27 of 29 chars are correct.
+    Command::cargo_bin("crabtype")?
+        .env("HOME", &home)
+        .args(["words", "5", "--list", "repeat"])
+        .write_stdin("Xello hello hello hello hellX\n")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("accuracy: 93.1%"));
+
+    std::fs::remove_dir_all(&home)?;
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//! Shared helpers for crabtype's CLI integration tests, so individual test
+//! files stay terse: `use common::*;` pulls in the command helper, the
+//! `Result` alias, and the proptest prelude.
+
+pub use assert_cmd::Command;
+pub use proptest::prelude::*;
+
+/// A `Result` alias for tests, so helpers can use `?` without spelling out
+/// the error type in every test function.
+pub type TestResult = Result<(), Box<dyn std::error::Error>>;
+
+/// Spawns the compiled `crabtype` binary with `$HOME` pointed at a path with
+/// no config file, so tests aren't affected by whatever config happens to
+/// exist on the machine running them.
+pub fn crabtype() -> Result<Command, Box<dyn std::error::Error>> {
+    let mut cmd = Command::cargo_bin("crabtype")?;
+    cmd.env("HOME", std::env::temp_dir().join("crabtype-test-home-does-not-exist"));
+    Ok(cmd)
+}